@@ -1,4 +1,7 @@
-use super::models::{PairScoresResponse, ScoresResponse};
+use super::models::{
+    ArbitrageResponse, HealthResponse, MaintenanceRequest, MaintenanceResponse,
+    PairArbitrageResponse, PairScoresResponse, ScoresResponse,
+};
 use crate::scoring::ScoringEngine;
 use axum::{
     extract::{Path, State},
@@ -7,15 +10,37 @@ use axum::{
 };
 use std::sync::Arc;
 
-/// GET /health — simple liveness check
-pub async fn health() -> &'static str {
-    "OK"
+/// GET /health — liveness check that also reports maintenance state
+pub async fn health(State(engine): State<Arc<ScoringEngine>>) -> Json<HealthResponse> {
+    let accepting = engine.is_accepting();
+    Json(HealthResponse {
+        status: if accepting { "ok" } else { "maintenance" },
+        accepting,
+    })
+}
+
+/// POST /maintenance — toggle maintenance mode. Order-book ingestion keeps
+/// running either way; this only gates what `/scores` and `/arbitrage` report.
+pub async fn set_maintenance(
+    State(engine): State<Arc<ScoringEngine>>,
+    Json(req): Json<MaintenanceRequest>,
+) -> Json<MaintenanceResponse> {
+    engine.set_accepting(req.accepting);
+    Json(MaintenanceResponse {
+        accepting: engine.is_accepting(),
+    })
 }
 
 /// GET /scores — all opportunities across all pairs and exchanges
-pub async fn get_all_scores(State(engine): State<Arc<ScoringEngine>>) -> Json<ScoresResponse> {
+pub async fn get_all_scores(
+    State(engine): State<Arc<ScoringEngine>>,
+) -> Result<Json<ScoresResponse>, StatusCode> {
+    if !engine.is_accepting() {
+        return Err(StatusCode::SERVICE_UNAVAILABLE);
+    }
+
     let scores = engine.compute_scores();
-    Json(ScoresResponse { scores })
+    Ok(Json(ScoresResponse { scores }))
 }
 
 /// GET /scores/:pair — opportunities for a specific pair (e.g. BTCUSDT)
@@ -23,6 +48,10 @@ pub async fn get_pair_scores(
     State(engine): State<Arc<ScoringEngine>>,
     Path(pair): Path<String>,
 ) -> Result<Json<PairScoresResponse>, StatusCode> {
+    if !engine.is_accepting() {
+        return Err(StatusCode::SERVICE_UNAVAILABLE);
+    }
+
     let pair = pair.to_uppercase();
     let scores: Vec<_> = engine
         .compute_scores()
@@ -36,3 +65,126 @@ pub async fn get_pair_scores(
 
     Ok(Json(PairScoresResponse { pair, scores }))
 }
+
+/// GET /arbitrage — cross-exchange arbitrage opportunities for all pairs
+pub async fn get_all_arbitrage(
+    State(engine): State<Arc<ScoringEngine>>,
+) -> Result<Json<ArbitrageResponse>, StatusCode> {
+    if !engine.is_accepting() {
+        return Err(StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    let opportunities = engine.compute_cross_exchange();
+    Ok(Json(ArbitrageResponse { opportunities }))
+}
+
+/// GET /arbitrage/:pair — cross-exchange arbitrage opportunities for a
+/// specific pair (e.g. BTCUSDT)
+pub async fn get_pair_arbitrage(
+    State(engine): State<Arc<ScoringEngine>>,
+    Path(pair): Path<String>,
+) -> Result<Json<PairArbitrageResponse>, StatusCode> {
+    if !engine.is_accepting() {
+        return Err(StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    let pair = pair.to_uppercase();
+    let opportunities: Vec<_> = engine
+        .compute_cross_exchange()
+        .into_iter()
+        .filter(|o| o.pair == pair)
+        .collect();
+
+    if opportunities.is_empty() {
+        return Err(StatusCode::NOT_FOUND);
+    }
+
+    Ok(Json(PairArbitrageResponse {
+        pair,
+        opportunities,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::funding::FundingRateStore;
+    use crate::orderbook::OrderBookStore;
+
+    fn test_engine() -> Arc<ScoringEngine> {
+        let config = Config {
+            pairs: vec!["BTCUSDT".to_string()],
+            api_port: 3000,
+            taker_fee_pct: 0.04,
+            maker_fee_pct: 0.02,
+            funding_weight: 1.0,
+        };
+        Arc::new(ScoringEngine::new(
+            OrderBookStore::new(),
+            FundingRateStore::new(),
+            config,
+        ))
+    }
+
+    #[tokio::test]
+    async fn health_reports_accepting_by_default() {
+        let engine = test_engine();
+        let Json(body) = health(State(Arc::clone(&engine))).await;
+
+        assert_eq!(body.status, "ok");
+        assert!(body.accepting);
+    }
+
+    #[tokio::test]
+    async fn set_maintenance_toggles_accepting_and_health_reflects_it() {
+        let engine = test_engine();
+
+        let Json(resp) = set_maintenance(
+            State(Arc::clone(&engine)),
+            Json(MaintenanceRequest { accepting: false }),
+        )
+        .await;
+        assert!(!resp.accepting);
+
+        let Json(health_body) = health(State(Arc::clone(&engine))).await;
+        assert_eq!(health_body.status, "maintenance");
+        assert!(!health_body.accepting);
+    }
+
+    #[tokio::test]
+    async fn get_all_scores_503s_during_maintenance() {
+        let engine = test_engine();
+        engine.set_accepting(false);
+
+        let result = get_all_scores(State(Arc::clone(&engine))).await;
+        assert_eq!(result.unwrap_err(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[tokio::test]
+    async fn get_all_arbitrage_503s_during_maintenance() {
+        let engine = test_engine();
+        engine.set_accepting(false);
+
+        let result = get_all_arbitrage(State(Arc::clone(&engine))).await;
+        assert_eq!(result.unwrap_err(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[tokio::test]
+    async fn get_pair_arbitrage_503s_during_maintenance() {
+        let engine = test_engine();
+        engine.set_accepting(false);
+
+        let result =
+            get_pair_arbitrage(State(Arc::clone(&engine)), Path("BTCUSDT".to_string())).await;
+        assert_eq!(result.unwrap_err(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[tokio::test]
+    async fn get_pair_scores_404s_when_pair_not_found() {
+        let engine = test_engine();
+        let result =
+            get_pair_scores(State(Arc::clone(&engine)), Path("DOGEUSDT".to_string())).await;
+        assert_eq!(result.unwrap_err(), StatusCode::NOT_FOUND);
+    }
+}
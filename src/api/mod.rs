@@ -6,17 +6,16 @@ use crate::config::Config;
 use crate::scoring::ScoringEngine;
 use std::net::SocketAddr;
 use std::sync::Arc;
+use tokio::sync::broadcast;
 
 pub struct ApiServer {
     engine: Arc<ScoringEngine>,
 }
 
 impl ApiServer {
-    /// Wraps the scoring engine in an Arc for shared handler access.
-    pub fn new(engine: ScoringEngine) -> Self {
-        Self {
-            engine: Arc::new(engine),
-        }
+    /// Takes the already-shared scoring engine for handler access.
+    pub fn new(engine: Arc<ScoringEngine>) -> Self {
+        Self { engine }
     }
 
     /// Binds the server to the configured port and starts serving.
@@ -31,4 +30,26 @@ impl ApiServer {
 
         Ok(())
     }
+
+    /// Like `run`, but stops serving as soon as `shutdown` fires, letting
+    /// in-flight requests finish instead of being dropped mid-response.
+    pub async fn run_with_broadcast(
+        self,
+        config: Config,
+        mut shutdown: broadcast::Receiver<()>,
+    ) -> anyhow::Result<()> {
+        let app = router::build(Arc::clone(&self.engine));
+        let addr = SocketAddr::from(([0, 0, 0, 0], config.api_port));
+
+        tracing::info!("API server listening on http://{}", addr);
+
+        let listener = tokio::net::TcpListener::bind(addr).await?;
+        axum::serve(listener, app)
+            .with_graceful_shutdown(async move {
+                let _ = shutdown.recv().await;
+            })
+            .await?;
+
+        Ok(())
+    }
 }
@@ -1,5 +1,24 @@
-use crate::scoring::ExchangeScore;
-use serde::Serialize;
+use crate::scoring::{ArbitrageOpportunity, ExchangeScore};
+use serde::{Deserialize, Serialize};
+
+/// Response for GET /health
+#[derive(Serialize)]
+pub struct HealthResponse {
+    pub status: &'static str,
+    pub accepting: bool,
+}
+
+/// Request body for POST /maintenance
+#[derive(Deserialize)]
+pub struct MaintenanceRequest {
+    pub accepting: bool,
+}
+
+/// Response for POST /maintenance
+#[derive(Serialize)]
+pub struct MaintenanceResponse {
+    pub accepting: bool,
+}
 
 /// Response for GET /scores
 #[derive(Serialize)]
@@ -13,3 +32,16 @@ pub struct PairScoresResponse {
     pub pair: String,
     pub scores: Vec<ExchangeScore>,
 }
+
+/// Response for GET /arbitrage
+#[derive(Serialize)]
+pub struct ArbitrageResponse {
+    pub opportunities: Vec<ArbitrageOpportunity>,
+}
+
+/// Response for GET /arbitrage/:pair
+#[derive(Serialize)]
+pub struct PairArbitrageResponse {
+    pub pair: String,
+    pub opportunities: Vec<ArbitrageOpportunity>,
+}
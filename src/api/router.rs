@@ -1,6 +1,6 @@
 use super::handlers;
 use crate::scoring::ScoringEngine;
-use axum::routing::get;
+use axum::routing::{get, post};
 use axum::Router;
 use std::sync::Arc;
 
@@ -8,7 +8,10 @@ use std::sync::Arc;
 pub fn build(engine: Arc<ScoringEngine>) -> Router {
     Router::new()
         .route("/health", get(handlers::health))
+        .route("/maintenance", post(handlers::set_maintenance))
         .route("/scores", get(handlers::get_all_scores))
         .route("/scores/:pair", get(handlers::get_pair_scores))
+        .route("/arbitrage", get(handlers::get_all_arbitrage))
+        .route("/arbitrage/:pair", get(handlers::get_pair_arbitrage))
         .with_state(engine)
 }
@@ -4,6 +4,15 @@ use std::env;
 pub struct Config {
     pub pairs: Vec<String>,
     pub api_port: u16,
+    /// Taker fee, in percent (e.g. 0.04 = 0.04%), charged on the leg of a
+    /// round trip that's entered aggressively.
+    pub taker_fee_pct: f64,
+    /// Maker fee, in percent, charged on the leg of a round trip that's
+    /// exited passively.
+    pub maker_fee_pct: f64,
+    /// How heavily captured funding is weighted against the cost to trade
+    /// in and out when ranking opportunities. 1.0 weighs them equally.
+    pub funding_weight: f64,
 }
 
 impl Config {
@@ -22,6 +31,30 @@ impl Config {
             .parse::<u16>()
             .expect("API_PORT must be a valid port number (1-65535)");
 
-        Self { pairs, api_port }
+        // defaults mirror Binance/Bybit USDT-M futures fee tiers
+        let taker_fee_pct = parse_f64_env("TAKER_FEE_PCT", 0.04);
+        let maker_fee_pct = parse_f64_env("MAKER_FEE_PCT", 0.02);
+        let funding_weight = parse_f64_env("FUNDING_WEIGHT", 1.0);
+
+        Self {
+            pairs,
+            api_port,
+            taker_fee_pct,
+            maker_fee_pct,
+            funding_weight,
+        }
+    }
+}
+
+/// Reads `key` as an `f64`, falling back to `default` when unset. Fails
+/// fast (like `API_PORT` above) rather than silently ignoring a typo'd
+/// override, so a malformed value is caught at startup instead of quietly
+/// skewing scores.
+fn parse_f64_env(key: &str, default: f64) -> f64 {
+    match env::var(key) {
+        Ok(v) => v
+            .parse::<f64>()
+            .unwrap_or_else(|_| panic!("{key} must be a valid number, got {v:?}")),
+        Err(_) => default,
     }
 }
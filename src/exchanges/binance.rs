@@ -1,7 +1,9 @@
 use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
 
-use super::Exchange;
+use super::{run_with_reconnect, Exchange};
 use crate::errors::ExchangeError;
+use crate::funding::RateSource;
 use crate::models::FundingRate;
 use crate::orderbook::OrderBook;
 use crate::{config::Config, orderbook::OrderBookStore};
@@ -9,6 +11,7 @@ use async_trait::async_trait;
 use futures_util::{SinkExt, StreamExt};
 use ordered_float::OrderedFloat;
 use serde::Deserialize;
+use tokio::sync::broadcast;
 use tokio_tungstenite::{connect_async, tungstenite::Message};
 
 /// The raw JSON shape Binance sends back
@@ -56,15 +59,16 @@ impl Binance {
     }
 }
 
-/// Connects to Binance's depth WebSocket stream for a single pair,
-/// reads messages in a loop, and logs the raw JSON.
-/// Returns an error if the connection fails or the stream closes unexpectedly.
-/// Runs indefinitely until the stream closes or an error occurs.
+/// Connects to Binance's depth WebSocket stream for a single pair and reads
+/// messages until the connection ends or errors. Returns how long the
+/// connection stayed up, so the caller's reconnect loop can decide whether
+/// to reset its backoff delay.
 async fn stream_pair(
     name: &'static str,
     pair: String,
     store: OrderBookStore,
-) -> Result<(), ExchangeError> {
+) -> Result<Duration, ExchangeError> {
+    let connected_at = Instant::now();
     let url = format!("wss://fstream.binance.com/ws/{pair}@depth20@100ms");
 
     tracing::info!("[{name}] {pair} stream connecting to {url}");
@@ -126,7 +130,7 @@ async fn stream_pair(
     }
 
     tracing::warn!("[{name}] {pair} stream closed");
-    Ok(())
+    Ok(connected_at.elapsed())
 }
 
 #[async_trait]
@@ -169,26 +173,39 @@ impl Exchange for Binance {
         })
     }
 
-    /// Spawns one tokio task per configured pair, each maintaining
-    /// a persistent WebSocket connection to Binance's order book stream.
-    /// Errors inside each task are logged but do not crash the others.
+    /// Spawns one tokio task per configured pair, each maintaining a
+    /// persistent WebSocket connection to Binance's order book stream.
+    /// Reconnects with exponential backoff on error or disconnect, and
+    /// stops cleanly once `shutdown` fires.
     async fn run_orderbook_stream(
         &self,
         config: &Config,
         store: OrderBookStore,
+        shutdown: broadcast::Sender<()>,
     ) -> Result<(), ExchangeError> {
         for pair in &config.pairs {
             let store = store.clone();
             let pair = pair.to_lowercase();
             let name = self.name();
+            let shutdown_rx = shutdown.subscribe();
 
             tokio::spawn(async move {
-                if let Err(e) = stream_pair(name, pair, store).await {
-                    tracing::error!("[{name}] stream error: {e}");
-                }
+                run_with_reconnect(name, &pair, shutdown_rx, || {
+                    stream_pair(name, pair.clone(), store.clone())
+                })
+                .await;
             });
         }
 
         Ok(())
     }
 }
+
+/// Binance's live funding rate, sourced from the same REST endpoint as
+/// `Exchange::fetch_funding_rate`.
+#[async_trait]
+impl RateSource for Binance {
+    async fn latest_rate(&self, pair: &str) -> Result<FundingRate, ExchangeError> {
+        self.fetch_funding_rate(pair).await
+    }
+}
@@ -1,8 +1,20 @@
-use super::Exchange;
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+use super::{run_with_reconnect, Exchange};
 use crate::errors::ExchangeError;
+use crate::funding::RateSource;
 use crate::models::FundingRate;
+use crate::orderbook::OrderBook;
+use crate::{config::Config, orderbook::OrderBookStore};
 use async_trait::async_trait;
+use futures_util::{SinkExt, StreamExt};
+use ordered_float::OrderedFloat;
 use serde::Deserialize;
+use tokio::sync::broadcast;
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+
+const WS_URL: &str = "wss://stream.bybit.com/v5/public/linear";
 
 #[derive(Debug, Deserialize)]
 struct BybitResponse {
@@ -27,6 +39,25 @@ struct BybitTicker {
     next_funding_time: String,
 }
 
+/// Shape of a message on Bybit's public `orderbook.50.<symbol>` topic.
+/// `type` is `"snapshot"` (replace the book) or `"delta"` (apply updates).
+#[derive(Debug, Deserialize)]
+struct OrderbookMessage {
+    topic: String,
+    #[serde(rename = "type")]
+    msg_type: String,
+    #[serde(rename = "ts")]
+    updated_ms: u64,
+    data: OrderbookData,
+}
+
+#[derive(Debug, Deserialize)]
+struct OrderbookData {
+    s: String,           // symbol
+    b: Vec<[String; 2]>, // bids: [price, qty]
+    a: Vec<[String; 2]>, // asks: [price, qty]
+}
+
 pub struct Bybit {
     client: reqwest::Client,
 }
@@ -39,6 +70,110 @@ impl Bybit {
     }
 }
 
+/// Applies `[price, qty]` level updates to `side`, removing a level when
+/// its size is `"0"`.
+fn apply_levels(
+    side: &mut BTreeMap<OrderedFloat<f64>, f64>,
+    levels: Vec<[String; 2]>,
+) -> Result<(), ExchangeError> {
+    for [price_str, qty_str] in levels {
+        let price = price_str
+            .parse::<f64>()
+            .map_err(|_| ExchangeError::UnexpectedData("invalid price".to_string()))?;
+        let qty = qty_str
+            .parse::<f64>()
+            .map_err(|_| ExchangeError::UnexpectedData("invalid quantity".to_string()))?;
+
+        let key = OrderedFloat(price);
+        if qty == 0.0 {
+            side.remove(&key);
+        } else {
+            side.insert(key, qty);
+        }
+    }
+
+    Ok(())
+}
+
+/// Connects to Bybit's public linear `orderbook.50.<symbol>` WebSocket
+/// stream for a single pair: the `snapshot` message replaces the book
+/// wholesale, and each `delta` message mutates it in place.
+async fn stream_pair(
+    name: &'static str,
+    pair: String,
+    store: OrderBookStore,
+) -> Result<Duration, ExchangeError> {
+    let connected_at = Instant::now();
+
+    tracing::info!("[{name}] {pair} stream connecting to {WS_URL}");
+
+    let (ws_stream, _) = connect_async(WS_URL)
+        .await
+        .map_err(|e| ExchangeError::WebSocket(e.to_string()))?;
+
+    let (mut write, mut read_stream) = ws_stream.split();
+
+    let subscribe = serde_json::json!({
+        "op": "subscribe",
+        "args": [format!("orderbook.50.{pair}")],
+    });
+
+    write
+        .send(Message::Text(subscribe.to_string()))
+        .await
+        .map_err(|e| ExchangeError::WebSocket(e.to_string()))?;
+
+    let mut book = OrderBook {
+        exchange: name,
+        pair: pair.clone(),
+        bids: BTreeMap::new(),
+        asks: BTreeMap::new(),
+        updated_ms: 0,
+    };
+
+    while let Some(msg) = read_stream.next().await {
+        let msg = msg.map_err(|e| ExchangeError::WebSocket(e.to_string()))?;
+
+        let Message::Text(text) = msg else {
+            continue;
+        };
+
+        // Subscription acks and pings don't carry a "topic" field — skip them.
+        let Ok(update) = serde_json::from_str::<OrderbookMessage>(&text) else {
+            tracing::debug!("[{name}] {pair} control message: {text}");
+            continue;
+        };
+
+        if !update.topic.starts_with("orderbook.") {
+            continue;
+        }
+
+        match update.msg_type.as_str() {
+            "snapshot" => {
+                book.bids.clear();
+                book.asks.clear();
+                apply_levels(&mut book.bids, update.data.b)?;
+                apply_levels(&mut book.asks, update.data.a)?;
+            }
+            "delta" => {
+                apply_levels(&mut book.bids, update.data.b)?;
+                apply_levels(&mut book.asks, update.data.a)?;
+            }
+            other => {
+                tracing::debug!("[{name}] {pair} unknown message type: {other}");
+                continue;
+            }
+        }
+
+        book.pair = update.data.s;
+        book.updated_ms = update.updated_ms;
+        store.update(book.clone());
+    }
+
+    tracing::warn!("[{name}] {pair} stream closed");
+    Ok(connected_at.elapsed())
+}
+
 #[async_trait]
 impl Exchange for Bybit {
     fn name(&self) -> &'static str {
@@ -91,4 +226,85 @@ impl Exchange for Bybit {
             next_funding_ms,
         })
     }
+
+    /// Spawns one tokio task per configured pair, each maintaining Bybit's
+    /// public linear orderbook stream with reconnect-on-failure behavior
+    /// matching the other exchanges.
+    async fn run_orderbook_stream(
+        &self,
+        config: &Config,
+        store: OrderBookStore,
+        shutdown: broadcast::Sender<()>,
+    ) -> Result<(), ExchangeError> {
+        for pair in &config.pairs {
+            let store = store.clone();
+            let pair = pair.clone();
+            let name = self.name();
+            let shutdown_rx = shutdown.subscribe();
+
+            tokio::spawn(async move {
+                run_with_reconnect(name, &pair, shutdown_rx, || {
+                    stream_pair(name, pair.clone(), store.clone())
+                })
+                .await;
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Bybit's live funding rate, sourced from the same REST endpoint as
+/// `Exchange::fetch_funding_rate`.
+#[async_trait]
+impl RateSource for Bybit {
+    async fn latest_rate(&self, pair: &str) -> Result<FundingRate, ExchangeError> {
+        self.fetch_funding_rate(pair).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn levels(pairs: &[(&str, &str)]) -> Vec<[String; 2]> {
+        pairs
+            .iter()
+            .map(|(price, qty)| [price.to_string(), qty.to_string()])
+            .collect()
+    }
+
+    #[test]
+    fn apply_levels_inserts_new_levels() {
+        let mut side = BTreeMap::new();
+        apply_levels(&mut side, levels(&[("100.5", "2.0"), ("101.0", "1.5")])).unwrap();
+
+        assert_eq!(side.get(&OrderedFloat(100.5)), Some(&2.0));
+        assert_eq!(side.get(&OrderedFloat(101.0)), Some(&1.5));
+    }
+
+    #[test]
+    fn apply_levels_replaces_quantity_at_existing_price() {
+        let mut side = BTreeMap::new();
+        apply_levels(&mut side, levels(&[("100.5", "2.0")])).unwrap();
+        apply_levels(&mut side, levels(&[("100.5", "3.25")])).unwrap();
+
+        assert_eq!(side.get(&OrderedFloat(100.5)), Some(&3.25));
+    }
+
+    #[test]
+    fn apply_levels_removes_level_on_zero_quantity() {
+        let mut side = BTreeMap::new();
+        apply_levels(&mut side, levels(&[("100.5", "2.0")])).unwrap();
+        apply_levels(&mut side, levels(&[("100.5", "0")])).unwrap();
+
+        assert!(side.get(&OrderedFloat(100.5)).is_none());
+    }
+
+    #[test]
+    fn apply_levels_rejects_unparseable_price() {
+        let mut side = BTreeMap::new();
+        let result = apply_levels(&mut side, levels(&[("not-a-price", "2.0")]));
+        assert!(result.is_err());
+    }
 }
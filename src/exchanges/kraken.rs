@@ -0,0 +1,512 @@
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+use super::{run_with_reconnect, Exchange};
+use crate::errors::ExchangeError;
+use crate::funding::RateSource;
+use crate::models::FundingRate;
+use crate::orderbook::OrderBook;
+use crate::{config::Config, orderbook::OrderBookStore};
+use async_trait::async_trait;
+use futures_util::{SinkExt, StreamExt};
+use ordered_float::OrderedFloat;
+use serde::Deserialize;
+use tokio::sync::broadcast;
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+
+const WS_URL: &str = "wss://ws.kraken.com";
+
+#[derive(Debug, Deserialize)]
+struct TickersResponse {
+    tickers: Vec<KrakenTicker>,
+}
+
+#[derive(Debug, Deserialize)]
+struct KrakenTicker {
+    symbol: String,
+
+    #[serde(rename = "fundingRate")]
+    funding_rate: f64,
+
+    #[serde(rename = "nextFundingRateTime")]
+    next_funding_rate_time: String,
+}
+
+pub struct Kraken {
+    client: reqwest::Client,
+}
+
+impl Kraken {
+    pub fn new() -> Self {
+        Self {
+            client: reqwest::Client::new(),
+        }
+    }
+}
+
+/// Kraken's spot pairs are quoted as e.g. "XBT/USD", not "BTCUSDT" like the
+/// rest of this crate — and Kraken calls bitcoin "XBT".
+fn to_kraken_ws_pair(pair: &str) -> String {
+    let base = pair.trim_end_matches("USDT").trim_end_matches("USD");
+    let base = if base == "BTC" { "XBT" } else { base };
+    format!("{base}/USD")
+}
+
+/// Kraken Futures perpetuals use their own symbol scheme, e.g. "PF_XBTUSD".
+fn to_kraken_futures_symbol(pair: &str) -> String {
+    let base = pair.trim_end_matches("USDT").trim_end_matches("USD");
+    let base = if base == "BTC" { "XBT" } else { base };
+    format!("PF_{base}USD")
+}
+
+/// Parses a Kraken-style ISO-8601 UTC timestamp (e.g.
+/// "2024-06-20T20:00:00.000Z") into milliseconds since the Unix epoch. A
+/// tiny hand-rolled parser rather than pulling in a datetime crate for this
+/// one field.
+fn parse_iso8601_ms(s: &str) -> Option<u64> {
+    let s = s.strip_suffix('Z').unwrap_or(s);
+    let (date, time) = s.split_once('T')?;
+
+    let mut date_parts = date.split('-');
+    let year: i64 = date_parts.next()?.parse().ok()?;
+    let month: i64 = date_parts.next()?.parse().ok()?;
+    let day: i64 = date_parts.next()?.parse().ok()?;
+
+    let mut time_parts = time.split(':');
+    let hour: i64 = time_parts.next()?.parse().ok()?;
+    let minute: i64 = time_parts.next()?.parse().ok()?;
+    let sec_field = time_parts.next()?;
+    let (sec, millis) = sec_field.split_once('.').unwrap_or((sec_field, "0"));
+    let second: i64 = sec.parse().ok()?;
+    let millis: i64 = format!("{millis:0<3}")[..3].parse().ok()?;
+
+    // Howard Hinnant's days_from_civil algorithm.
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = (if y >= 0 { y } else { y - 399 }) / 400;
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days_since_epoch = era * 146_097 + doe - 719_468;
+
+    let total_seconds = days_since_epoch * 86_400 + hour * 3600 + minute * 60 + second;
+    Some((total_seconds * 1000 + millis) as u64)
+}
+
+/// A single book level, keeping the exchange's original price/volume
+/// strings alongside the parsed price used as the sort key. Kraken's
+/// checksum is computed over the *raw* digits (decimal point and leading
+/// zeros stripped), so reformatting from `f64` would drift from what the
+/// exchange expects.
+struct Level {
+    price_raw: String,
+    qty_raw: String,
+}
+
+/// Applies a batch of `[price, volume, timestamp]` levels (Kraken's wire
+/// format) to `side`, removing a level when its volume is `"0"`.
+fn apply_levels(
+    side: &mut BTreeMap<OrderedFloat<f64>, Level>,
+    levels: &[serde_json::Value],
+) -> Result<(), ExchangeError> {
+    for level in levels {
+        let level = level
+            .as_array()
+            .ok_or_else(|| ExchangeError::UnexpectedData("malformed book level".to_string()))?;
+
+        let price_raw = level
+            .first()
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| ExchangeError::UnexpectedData("missing price".to_string()))?;
+
+        let qty_raw = level
+            .get(1)
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| ExchangeError::UnexpectedData("missing quantity".to_string()))?;
+
+        let price: f64 = price_raw
+            .parse()
+            .map_err(|_| ExchangeError::UnexpectedData("invalid price".to_string()))?;
+        let qty: f64 = qty_raw
+            .parse()
+            .map_err(|_| ExchangeError::UnexpectedData("invalid quantity".to_string()))?;
+
+        let key = OrderedFloat(price);
+        if qty == 0.0 {
+            side.remove(&key);
+        } else {
+            side.insert(
+                key,
+                Level {
+                    price_raw: price_raw.to_string(),
+                    qty_raw: qty_raw.to_string(),
+                },
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Strips the decimal point and any leading zeros from a raw price/volume
+/// string, as Kraken's checksum spec requires.
+fn checksum_component(raw: &str) -> String {
+    let digits: String = raw.chars().filter(|c| *c != '.').collect();
+    let trimmed = digits.trim_start_matches('0');
+
+    if trimmed.is_empty() {
+        "0".to_string()
+    } else {
+        trimmed.to_string()
+    }
+}
+
+/// Builds Kraken's book checksum: top 10 asks ascending by price, then top
+/// 10 bids descending by price, each level's price and volume concatenated
+/// after stripping the decimal point and leading zeros, CRC32'd.
+fn book_checksum(
+    asks: &BTreeMap<OrderedFloat<f64>, Level>,
+    bids: &BTreeMap<OrderedFloat<f64>, Level>,
+) -> u32 {
+    let mut buf = String::new();
+
+    for level in asks.values().take(10) {
+        buf.push_str(&checksum_component(&level.price_raw));
+        buf.push_str(&checksum_component(&level.qty_raw));
+    }
+
+    for level in bids.values().rev().take(10) {
+        buf.push_str(&checksum_component(&level.price_raw));
+        buf.push_str(&checksum_component(&level.qty_raw));
+    }
+
+    crc32fast::hash(buf.as_bytes())
+}
+
+fn to_orderbook(
+    name: &'static str,
+    pair: &str,
+    asks: &BTreeMap<OrderedFloat<f64>, Level>,
+    bids: &BTreeMap<OrderedFloat<f64>, Level>,
+) -> OrderBook {
+    let updated_ms = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64;
+
+    OrderBook {
+        exchange: name,
+        pair: pair.to_string(),
+        asks: asks
+            .iter()
+            .map(|(price, level)| (*price, level.qty_raw.parse().unwrap_or(0.0)))
+            .collect(),
+        bids: bids
+            .iter()
+            .map(|(price, level)| (*price, level.qty_raw.parse().unwrap_or(0.0)))
+            .collect(),
+        updated_ms,
+    }
+}
+
+/// Connects to Kraken's `book` WebSocket feed for a single pair, applies
+/// the initial snapshot, then mutates the book in place as `a`/`b` deltas
+/// arrive. Validates every delta against Kraken's CRC32 checksum field; on
+/// a mismatch, discards the local book and ends the connection so the
+/// caller's reconnect loop resubscribes from a fresh snapshot.
+async fn stream_pair(
+    name: &'static str,
+    pair: String,
+    ws_pair: String,
+    store: OrderBookStore,
+) -> Result<Duration, ExchangeError> {
+    let connected_at = Instant::now();
+
+    tracing::info!("[{name}] {pair} stream connecting to {WS_URL} ({ws_pair})");
+
+    let (ws_stream, _) = connect_async(WS_URL)
+        .await
+        .map_err(|e| ExchangeError::WebSocket(e.to_string()))?;
+
+    let (mut write, mut read_stream) = ws_stream.split();
+
+    let subscribe = serde_json::json!({
+        "event": "subscribe",
+        "pair": [ws_pair],
+        "subscription": { "name": "book", "depth": 10 },
+    });
+
+    write
+        .send(Message::Text(subscribe.to_string()))
+        .await
+        .map_err(|e| ExchangeError::WebSocket(e.to_string()))?;
+
+    let mut asks = BTreeMap::<OrderedFloat<f64>, Level>::new();
+    let mut bids = BTreeMap::<OrderedFloat<f64>, Level>::new();
+
+    while let Some(msg) = read_stream.next().await {
+        let msg = msg.map_err(|e| ExchangeError::WebSocket(e.to_string()))?;
+
+        let Message::Text(text) = msg else {
+            continue;
+        };
+
+        let value: serde_json::Value = serde_json::from_str(&text).map_err(ExchangeError::Parse)?;
+
+        // Handshake/status messages (systemStatus, subscriptionStatus, ...)
+        // arrive as JSON objects; book data arrives as untagged arrays.
+        let Some(frame) = value.as_array() else {
+            tracing::debug!("[{name}] {pair} control message: {text}");
+            continue;
+        };
+
+        if frame.len() < 4 {
+            continue;
+        }
+
+        let mut is_snapshot = false;
+        let mut checksum: Option<u32> = None;
+
+        for entry in &frame[1..frame.len() - 2] {
+            let Some(obj) = entry.as_object() else {
+                continue;
+            };
+
+            if let Some(levels) = obj.get("as").and_then(|v| v.as_array()) {
+                is_snapshot = true;
+                apply_levels(&mut asks, levels)?;
+            }
+            if let Some(levels) = obj.get("bs").and_then(|v| v.as_array()) {
+                is_snapshot = true;
+                apply_levels(&mut bids, levels)?;
+            }
+            if let Some(levels) = obj.get("a").and_then(|v| v.as_array()) {
+                apply_levels(&mut asks, levels)?;
+            }
+            if let Some(levels) = obj.get("b").and_then(|v| v.as_array()) {
+                apply_levels(&mut bids, levels)?;
+            }
+            if let Some(c) = obj.get("c").and_then(|v| v.as_str()) {
+                checksum = c.parse::<u32>().ok();
+            }
+        }
+
+        if is_snapshot {
+            tracing::debug!("[{name}] {pair} snapshot received");
+            store.update(to_orderbook(name, &pair, &asks, &bids));
+            continue;
+        }
+
+        if let Some(expected) = checksum {
+            let actual = book_checksum(&asks, &bids);
+            if actual != expected {
+                tracing::warn!(
+                    "[{name}] {pair} checksum mismatch (expected {expected}, got {actual}) — discarding book and resubscribing"
+                );
+                store.remove(name, &pair);
+                return Ok(connected_at.elapsed());
+            }
+        }
+
+        store.update(to_orderbook(name, &pair, &asks, &bids));
+    }
+
+    tracing::warn!("[{name}] {pair} stream closed");
+    Ok(connected_at.elapsed())
+}
+
+#[async_trait]
+impl Exchange for Kraken {
+    fn name(&self) -> &'static str {
+        "kraken"
+    }
+
+    /// Fetches the current funding rate for a perpetual future via REST.
+    /// Kraken Futures uses its own symbol scheme (e.g. "PF_XBTUSD"), so the
+    /// crate's canonical pair name is translated before hitting the API.
+    async fn fetch_funding_rate(&self, pair: &str) -> Result<FundingRate, ExchangeError> {
+        let symbol = to_kraken_futures_symbol(pair);
+        let url = "https://futures.kraken.com/derivatives/api/v3/tickers";
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(ExchangeError::Http)?
+            .json::<TickersResponse>()
+            .await
+            .map_err(ExchangeError::Http)?;
+
+        let ticker = response
+            .tickers
+            .into_iter()
+            .find(|t| t.symbol == symbol)
+            .ok_or_else(|| {
+                ExchangeError::UnexpectedData(format!("Kraken has no ticker for {symbol}"))
+            })?;
+
+        let next_funding_ms =
+            parse_iso8601_ms(&ticker.next_funding_rate_time).ok_or_else(|| {
+                ExchangeError::UnexpectedData(format!(
+                    "unparseable nextFundingRateTime: {}",
+                    ticker.next_funding_rate_time
+                ))
+            })?;
+
+        Ok(FundingRate {
+            exchange: self.name(),
+            pair: pair.to_string(),
+            rate: ticker.funding_rate,
+            next_funding_ms,
+        })
+    }
+
+    /// Spawns one tokio task per configured pair, each maintaining Kraken's
+    /// delta-based order book feed with reconnect-on-failure behavior
+    /// matching the other exchanges.
+    async fn run_orderbook_stream(
+        &self,
+        config: &Config,
+        store: OrderBookStore,
+        shutdown: broadcast::Sender<()>,
+    ) -> Result<(), ExchangeError> {
+        for pair in &config.pairs {
+            let store = store.clone();
+            let pair = pair.clone();
+            let ws_pair = to_kraken_ws_pair(&pair);
+            let name = self.name();
+            let shutdown_rx = shutdown.subscribe();
+
+            tokio::spawn(async move {
+                run_with_reconnect(name, &pair, shutdown_rx, || {
+                    stream_pair(name, pair.clone(), ws_pair.clone(), store.clone())
+                })
+                .await;
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Kraken's live funding rate, sourced from the same REST endpoint as
+/// `Exchange::fetch_funding_rate`.
+#[async_trait]
+impl RateSource for Kraken {
+    async fn latest_rate(&self, pair: &str) -> Result<FundingRate, ExchangeError> {
+        self.fetch_funding_rate(pair).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_iso8601_ms_epoch() {
+        assert_eq!(parse_iso8601_ms("1970-01-01T00:00:00.000Z"), Some(0));
+    }
+
+    #[test]
+    fn parse_iso8601_ms_with_millis() {
+        // date -u -d "2021-01-05T12:34:56.789Z" +%s == 1609850096
+        assert_eq!(
+            parse_iso8601_ms("2021-01-05T12:34:56.789Z"),
+            Some(1_609_850_096_789)
+        );
+    }
+
+    #[test]
+    fn parse_iso8601_ms_without_millis() {
+        // date -u -d "2024-06-20T20:00:00.000Z" +%s == 1718913600
+        assert_eq!(
+            parse_iso8601_ms("2024-06-20T20:00:00Z"),
+            Some(1_718_913_600_000)
+        );
+    }
+
+    #[test]
+    fn parse_iso8601_ms_rejects_malformed_input() {
+        assert_eq!(parse_iso8601_ms("not-a-timestamp"), None);
+        assert_eq!(parse_iso8601_ms("2024-06-20"), None);
+    }
+
+    #[test]
+    fn checksum_component_strips_decimal_point() {
+        assert_eq!(checksum_component("5541.20000"), "554120000");
+    }
+
+    #[test]
+    fn checksum_component_strips_leading_zeros() {
+        assert_eq!(checksum_component("0.00001230"), "1230");
+    }
+
+    #[test]
+    fn checksum_component_all_zero_collapses_to_single_zero() {
+        assert_eq!(checksum_component("0.00000000"), "0");
+    }
+
+    #[test]
+    fn book_checksum_matches_manually_built_string() {
+        let mut asks = BTreeMap::new();
+        asks.insert(
+            OrderedFloat(5541.2),
+            Level {
+                price_raw: "5541.20000".to_string(),
+                qty_raw: "2.50000000".to_string(),
+            },
+        );
+
+        let mut bids = BTreeMap::new();
+        bids.insert(
+            OrderedFloat(5541.0),
+            Level {
+                price_raw: "5541.00000".to_string(),
+                qty_raw: "1.00000000".to_string(),
+            },
+        );
+
+        let expected = crc32fast::hash("554120000250000000554100000100000000".as_bytes());
+        assert_eq!(book_checksum(&asks, &bids), expected);
+    }
+
+    #[test]
+    fn book_checksum_takes_top_10_per_side_in_order() {
+        // Asks ascend by price, bids descend by price, each capped at 10
+        // levels — verify both the ordering and the cap with 11 levels a side.
+        // Raw strings here deliberately have no decimal point, so
+        // `checksum_component` is just the digits unchanged.
+        let mut asks = BTreeMap::new();
+        let mut bids = BTreeMap::new();
+        for i in 0..11u32 {
+            asks.insert(
+                OrderedFloat(100.0 + i as f64),
+                Level {
+                    price_raw: (100 + i).to_string(),
+                    qty_raw: "1".to_string(),
+                },
+            );
+            bids.insert(
+                OrderedFloat(50.0 + i as f64),
+                Level {
+                    price_raw: (50 + i).to_string(),
+                    qty_raw: "1".to_string(),
+                },
+            );
+        }
+
+        let mut expected_buf = String::new();
+        for i in 0..10u32 {
+            expected_buf.push_str(&(100 + i).to_string());
+            expected_buf.push('1');
+        }
+        for i in (1..11u32).rev() {
+            expected_buf.push_str(&(50 + i).to_string());
+            expected_buf.push('1');
+        }
+
+        let expected = crc32fast::hash(expected_buf.as_bytes());
+        assert_eq!(book_checksum(&asks, &bids), expected);
+    }
+}
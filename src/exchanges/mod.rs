@@ -3,9 +3,13 @@ use crate::errors::ExchangeError;
 use crate::models::FundingRate;
 use crate::orderbook::OrderBookStore;
 use async_trait::async_trait;
+use std::future::Future;
+use std::time::{Duration, Instant};
+use tokio::sync::broadcast;
 
 pub mod binance;
 pub mod bybit;
+pub mod kraken;
 
 #[async_trait]
 pub trait Exchange: Send + Sync {
@@ -14,10 +18,163 @@ pub trait Exchange: Send + Sync {
     async fn fetch_funding_rate(&self, pair: &str) -> Result<FundingRate, ExchangeError>;
 
     /// Spawn a tokio task that connects to this exchange's order book Websocket
-    /// and continuously updates the store.
+    /// and continuously updates the store. `shutdown` is the broadcast sender
+    /// used to derive a fresh receiver per spawned task, so each stream can
+    /// stop reconnecting cleanly on Ctrl+C.
     async fn run_orderbook_stream(
         &self,
         config: &Config,
         store: OrderBookStore,
+        shutdown: broadcast::Sender<()>,
     ) -> Result<(), ExchangeError>;
 }
+
+/// Initial reconnect delay.
+const INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+/// Reconnect delay cap.
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+/// How long a connection needs to stay up before we treat it as healthy
+/// again and reset the backoff delay back to `INITIAL_BACKOFF`.
+const SUSTAINED_CONNECTION: Duration = Duration::from_secs(30);
+
+/// Runs `connect` in a loop with exponential backoff (plus jitter) between
+/// attempts, stopping cleanly as soon as `shutdown` fires. `connect` should
+/// run until its WebSocket connection ends and report how long it stayed
+/// up; an attempt that ends with an error is timed here instead. Either
+/// way, the backoff resets after a connection that sustained message flow
+/// for a while. Shared by every
+/// `Exchange::run_orderbook_stream` implementation so reconnection behaves
+/// the same everywhere.
+pub(crate) async fn run_with_reconnect<F, Fut>(
+    name: &'static str,
+    label: &str,
+    mut shutdown: broadcast::Receiver<()>,
+    mut connect: F,
+) where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<Duration, ExchangeError>>,
+{
+    let mut backoff = INITIAL_BACKOFF;
+
+    loop {
+        let started = Instant::now();
+        tokio::select! {
+            _ = shutdown.recv() => {
+                tracing::info!("[{name}] {label} stream shutting down");
+                return;
+            }
+            result = connect() => {
+                match result {
+                    Ok(uptime) if uptime >= SUSTAINED_CONNECTION => backoff = INITIAL_BACKOFF,
+                    Ok(_) => {}
+                    Err(e) => {
+                        tracing::warn!("[{name}] {label} stream error: {e}");
+                        if started.elapsed() >= SUSTAINED_CONNECTION {
+                            backoff = INITIAL_BACKOFF;
+                        }
+                    }
+                }
+            }
+        }
+
+        let sleep_for = jitter(backoff);
+        tracing::info!("[{name}] {label} reconnecting in {sleep_for:?}");
+
+        tokio::select! {
+            _ = tokio::time::sleep(sleep_for) => {}
+            _ = shutdown.recv() => {
+                tracing::info!("[{name}] {label} stream shutting down during backoff");
+                return;
+            }
+        }
+
+        backoff = (backoff * 2).min(MAX_BACKOFF);
+    }
+}
+
+/// Adds up to ±20% jitter to `base`, derived from the system clock rather
+/// than pulling in a `rand` dependency for a single call site.
+fn jitter(base: Duration) -> Duration {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .subsec_nanos() as u64;
+
+    let spread_ms = (base.as_millis() as u64 / 5).max(1); // ±20%
+    let offset_ms = (nanos % (spread_ms * 2)) as i64 - spread_ms as i64;
+    let millis = (base.as_millis() as i64 + offset_ms).max(0) as u64;
+
+    Duration::from_millis(millis)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+    use std::sync::Arc;
+
+    #[test]
+    fn jitter_stays_within_plus_minus_20_percent() {
+        for base_ms in [1_000u64, 5_000, 60_000] {
+            let base = Duration::from_millis(base_ms);
+            let spread_ms = (base_ms / 5) as i64;
+
+            // Jitter is derived from the clock, so sample a handful of times
+            // rather than relying on a single draw.
+            for _ in 0..20 {
+                let jittered = jitter(base).as_millis() as i64;
+                assert!(jittered >= base_ms as i64 - spread_ms);
+                assert!(jittered <= base_ms as i64 + spread_ms);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn run_with_reconnect_stops_once_shutdown_fires() {
+        let (shutdown_tx, shutdown_rx) = broadcast::channel::<()>(1);
+        let attempts = Arc::new(AtomicUsize::new(0));
+
+        let attempts_clone = Arc::clone(&attempts);
+        let shutdown_tx_clone = shutdown_tx.clone();
+
+        run_with_reconnect("test", "pair", shutdown_rx, move || {
+            let attempts = Arc::clone(&attempts_clone);
+            let shutdown_tx = shutdown_tx_clone.clone();
+            async move {
+                attempts.fetch_add(1, AtomicOrdering::SeqCst);
+                // Simulate the connection ending, then request shutdown so
+                // the loop exits during its backoff wait instead of
+                // reconnecting forever.
+                let _ = shutdown_tx.send(());
+                Ok(Duration::from_secs(0))
+            }
+        })
+        .await;
+
+        assert_eq!(attempts.load(AtomicOrdering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn run_with_reconnect_accepts_a_sustained_connection_report() {
+        let (shutdown_tx, shutdown_rx) = broadcast::channel::<()>(1);
+        let attempts = Arc::new(AtomicUsize::new(0));
+
+        let attempts_clone = Arc::clone(&attempts);
+        let shutdown_tx_clone = shutdown_tx.clone();
+
+        run_with_reconnect("test", "pair", shutdown_rx, move || {
+            let attempts = Arc::clone(&attempts_clone);
+            let shutdown_tx = shutdown_tx_clone.clone();
+            async move {
+                attempts.fetch_add(1, AtomicOrdering::SeqCst);
+                let _ = shutdown_tx.send(());
+                // uptime >= SUSTAINED_CONNECTION should reset backoff rather
+                // than panic or otherwise misbehave.
+                Ok(SUSTAINED_CONNECTION)
+            }
+        })
+        .await;
+
+        assert_eq!(attempts.load(AtomicOrdering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,99 @@
+pub mod store;
+
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use async_trait::async_trait;
+pub use store::FundingRateStore;
+
+use crate::errors::ExchangeError;
+use crate::models::FundingRate;
+
+/// A pluggable source of funding-rate data, decoupled from the `Exchange`
+/// trait's order-book streaming. Lets the refresh loop be pointed at a live
+/// REST poller in production or a `FixedRate` stub in tests, without either
+/// side knowing about the other.
+#[async_trait]
+pub trait RateSource: Send + Sync {
+    /// Returns the latest known funding rate for `pair`.
+    async fn latest_rate(&self, pair: &str) -> Result<FundingRate, ExchangeError>;
+}
+
+/// Test/dev `RateSource` that always returns the same rate, regardless of
+/// `pair`. Useful for exercising the scoring engine without hitting a real
+/// exchange.
+pub struct FixedRate {
+    rate: FundingRate,
+}
+
+impl FixedRate {
+    pub fn new(rate: FundingRate) -> Self {
+        Self { rate }
+    }
+}
+
+#[async_trait]
+impl RateSource for FixedRate {
+    async fn latest_rate(&self, _pair: &str) -> Result<FundingRate, ExchangeError> {
+        Ok(self.rate.clone())
+    }
+}
+
+/// Fallback wait before retrying a failed poll, and the refresh interval
+/// used when the exchange's `next_funding_ms` can't be trusted (e.g. it's
+/// already in the past).
+const FALLBACK_REFRESH: Duration = Duration::from_secs(5 * 60);
+
+/// Polls `source` for `pair`'s funding rate, writes each result into `store`,
+/// then sleeps until the exchange-reported `next_funding_ms` before polling
+/// again. Runs indefinitely — spawn this as its own task per exchange/pair.
+pub async fn run_refresh_loop(source: Arc<dyn RateSource>, pair: String, store: FundingRateStore) {
+    loop {
+        let sleep_for = match source.latest_rate(&pair).await {
+            Ok(rate) => {
+                let now_ms = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_millis() as u64;
+
+                let until_next = rate.next_funding_ms.saturating_sub(now_ms);
+                store.update(rate);
+
+                if until_next > 0 {
+                    Duration::from_millis(until_next)
+                } else {
+                    FALLBACK_REFRESH
+                }
+            }
+            Err(e) => {
+                tracing::error!("funding rate refresh failed for {pair}: {e}");
+                FALLBACK_REFRESH
+            }
+        };
+
+        tokio::time::sleep(sleep_for).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn fixed_rate_returns_same_rate_for_any_pair() {
+        let rate = FundingRate {
+            exchange: "test",
+            pair: "BTCUSDT".to_string(),
+            rate: 0.0001,
+            next_funding_ms: 1_700_000_000_000,
+        };
+        let source = FixedRate::new(rate.clone());
+
+        let btc = source.latest_rate("BTCUSDT").await.unwrap();
+        let eth = source.latest_rate("ETHUSDT").await.unwrap();
+
+        assert_eq!(btc.rate, rate.rate);
+        assert_eq!(eth.rate, rate.rate);
+        assert_eq!(eth.next_funding_ms, rate.next_funding_ms);
+    }
+}
@@ -0,0 +1,39 @@
+use crate::models::FundingRate;
+use dashmap::DashMap;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct FundingRateStore {
+    inner: Arc<DashMap<String, FundingRate>>,
+}
+
+impl FundingRateStore {
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(DashMap::new()),
+        }
+    }
+
+    /// Key format e.g.: "binance:BTCUSDT"
+    fn key(exchange: &str, pair: &str) -> String {
+        format!("{}:{}", exchange, pair)
+    }
+
+    /// Writes the new funding rate into the store
+    pub fn update(&self, fr: FundingRate) {
+        self.inner
+            .insert(Self::key(fr.exchange, fr.pair.as_str()), fr);
+    }
+
+    /// Gets a funding rate from the store
+    pub fn get(&self, exchange: &str, pair: &str) -> Option<FundingRate> {
+        self.inner
+            .get(&Self::key(exchange, pair))
+            .map(|r| r.clone())
+    }
+
+    /// Gets all stored funding rates
+    pub fn all(&self) -> Vec<FundingRate> {
+        self.inner.iter().map(|r| r.value().clone()).collect()
+    }
+}
@@ -2,6 +2,7 @@ mod api;
 mod config;
 mod errors;
 mod exchanges;
+mod funding;
 mod models;
 mod orderbook;
 mod scoring;
@@ -11,7 +12,9 @@ use std::sync::Arc;
 use config::Config;
 use exchanges::binance::Binance;
 use exchanges::bybit::Bybit;
+use exchanges::kraken::Kraken;
 use exchanges::Exchange;
+use funding::{FundingRateStore, RateSource};
 use orderbook::store::OrderBookStore;
 use scoring::ScoringEngine;
 
@@ -46,29 +49,48 @@ async fn main() {
         let _ = shutdown_tx_signal.send(()); // Broadcast to ALL subscribers
     });
 
-    // ── 1. Fetch funding rates (one-off at startup) ────────────────
-    let exchanges: Vec<Box<dyn Exchange>> = vec![Box::new(Binance::new()), Box::new(Bybit::new())];
-
-    for ex in &exchanges {
-        match ex.fetch_funding_rate("BTCUSDT").await {
-            Ok(fr) => tracing::info!(
-                "[{}] BTCUSDT funding rate: {:.4}%",
-                fr.exchange,
-                fr.rate * 100.0
-            ),
-            Err(e) => tracing::error!("[{}] Failed: {}", ex.name(), e),
+    // ── 1. Spawn funding-rate refresh loops (continuous, not one-off) ──
+    let funding_store = FundingRateStore::new();
+    let rate_sources: Vec<Arc<dyn RateSource>> = vec![
+        Arc::new(Binance::new()),
+        Arc::new(Bybit::new()),
+        Arc::new(Kraken::new()),
+    ];
+
+    for source in &rate_sources {
+        for pair in &config.pairs {
+            let source = Arc::clone(source);
+            let pair = pair.clone();
+            let store = funding_store.clone();
+
+            tokio::spawn(async move {
+                funding::run_refresh_loop(source, pair, store).await;
+            });
         }
     }
 
+    let exchanges: Vec<Box<dyn Exchange>> = vec![
+        Box::new(Binance::new()),
+        Box::new(Bybit::new()),
+        Box::new(Kraken::new()),
+    ];
+
     // ── 2. Spawn WebSocket order book streams ──────────────────────
     for ex in &exchanges {
-        if let Err(e) = ex.run_orderbook_stream(&config, store.clone()).await {
+        if let Err(e) = ex
+            .run_orderbook_stream(&config, store.clone(), shutdown_tx.clone())
+            .await
+        {
             tracing::error!("[{}] Failed to start stream: {}", ex.name(), e);
         }
     }
 
     // ── 3. Create scoring engine ───────────────────────────────────
-    let scoring_engine = Arc::new(ScoringEngine::new(store.clone()));
+    let scoring_engine = Arc::new(ScoringEngine::new(
+        store.clone(),
+        funding_store.clone(),
+        config.clone(),
+    ));
 
     // ── 4. Spawn scoring loop ──────────────────────────────────────
     let scoring_engine_clone = Arc::clone(&scoring_engine);
@@ -27,6 +27,16 @@ impl OrderBook {
         self.asks.keys().next().map(|p| p.into_inner())
     }
 
+    /// Quantity available at the best bid
+    pub fn best_bid_qty(&self) -> Option<f64> {
+        self.bids.iter().next_back().map(|(_, qty)| *qty)
+    }
+
+    /// Quantity available at the best ask
+    pub fn best_ask_qty(&self) -> Option<f64> {
+        self.asks.iter().next().map(|(_, qty)| *qty)
+    }
+
     /// Spread between best ask and best bid
     pub fn spread(&self) -> Option<f64> {
         Some(self.best_ask()? - self.best_bid()?)
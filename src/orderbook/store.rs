@@ -36,4 +36,11 @@ impl OrderBookStore {
     pub fn all(&self) -> Vec<OrderBook> {
         self.inner.iter().map(|r| r.value().clone()).collect()
     }
+
+    /// Drops a stored order book, e.g. after a delta-feed integrity check
+    /// (like Kraken's checksum) fails and the book needs to be rebuilt from
+    /// a fresh snapshot.
+    pub fn remove(&self, exchange: &str, pair: &str) {
+        self.inner.remove(&Self::key(exchange, pair));
+    }
 }
@@ -1,37 +1,97 @@
 use crate::config::Config;
+use crate::funding::FundingRateStore;
 use crate::orderbook::{OrderBook, OrderBookStore};
+use ordered_float::OrderedFloat;
+use serde::Serialize;
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 /// Composite score for a given pair across all exchanges.
-/// Higher = better opportunity (negative funding + tight spreads).
-#[derive(Debug, Clone)]
+/// Higher = better opportunity (funding captured outweighs the cost to
+/// trade in and out).
+#[derive(Debug, Clone, Serialize)]
 pub struct ExchangeScore {
     pub exchange: String,
     pub pair: String,
     pub best_bid: f64,
     pub best_ask: f64,
     pub spread_pct: f64,
+    pub funding_rate_pct: f64,
     pub score: f64,
 }
 
+/// A cross-exchange arbitrage opportunity: buy on one exchange's ask,
+/// sell on another exchange's bid, for the same pair.
+#[derive(Debug, Clone, Serialize)]
+pub struct ArbitrageOpportunity {
+    pub pair: String,
+    pub buy_exchange: String,
+    pub sell_exchange: String,
+    pub buy_price: f64,
+    pub sell_price: f64,
+    /// Gross edge in percent, before fees or funding.
+    pub edge_pct: f64,
+    /// Executable notional, in quote currency (e.g. USDT), limited by the
+    /// thinner side of the top-of-book quantities on the buy and sell
+    /// exchanges and priced at the buy-side ask.
+    pub quote_size: f64,
+}
+
 pub struct ScoringEngine {
     store: OrderBookStore,
+    funding_store: FundingRateStore,
+    config: Config,
+    /// Whether the public API should currently serve opportunities, or
+    /// quiesce for maintenance. Order-book ingestion ignores this flag
+    /// entirely — it only gates what the API reports. `ScoringEngine` is
+    /// always shared behind a single `Arc` (see `main.rs`), so this field
+    /// doesn't need its own `Arc`.
+    accepting: AtomicBool,
 }
 
 impl ScoringEngine {
-    pub fn new(store: OrderBookStore) -> Self {
-        Self { store }
+    pub fn new(store: OrderBookStore, funding_store: FundingRateStore, config: Config) -> Self {
+        Self {
+            store,
+            funding_store,
+            config,
+            accepting: AtomicBool::new(true),
+        }
+    }
+
+    /// Whether the public API should currently serve scores.
+    pub fn is_accepting(&self) -> bool {
+        self.accepting.load(Ordering::Relaxed)
+    }
+
+    /// Toggles maintenance mode on or off.
+    pub fn set_accepting(&self, accepting: bool) {
+        self.accepting.store(accepting, Ordering::Relaxed);
     }
 
     /// Compute arbitrage opportunities and exchange rankings for all pairs.
     /// Returns top exchanges per pair, sorted by score.
+    ///
+    /// score = (funding_rate_pct * funding_weight) - (spread_pct + round-trip fees)
+    ///
+    /// i.e. net edge: what you'd collect in funding, minus what it costs to
+    /// enter on the spread and pay fees on both legs.
     pub fn compute_scores(&self) -> Vec<ExchangeScore> {
         let mut scores = Vec::<ExchangeScore>::new();
+        let round_trip_fee_pct = self.config.taker_fee_pct + self.config.maker_fee_pct;
 
         for orderbook in self.store.all() {
             if let (Some(best_bid), Some(best_ask)) = (orderbook.best_bid(), orderbook.best_ask()) {
                 let spread_pct = (best_ask - best_bid) / best_bid * 100.0;
-                let score = 100.0 / spread_pct - spread_pct; // simple heuristic
+                let effective_spread_pct = spread_pct + round_trip_fee_pct;
+
+                let funding_rate_pct = self
+                    .funding_store
+                    .get(orderbook.exchange, &orderbook.pair)
+                    .map(|fr| fr.rate * 100.0)
+                    .unwrap_or(0.0);
+
+                let score = funding_rate_pct * self.config.funding_weight - effective_spread_pct;
 
                 scores.push(ExchangeScore {
                     exchange: orderbook.exchange.to_string(),
@@ -39,21 +99,91 @@ impl ScoringEngine {
                     best_bid,
                     best_ask,
                     spread_pct,
+                    funding_rate_pct,
                     score,
                 });
             }
         }
 
-        // Sort descending by score (best first)
-        scores.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap());
+        // Sort descending by score (best first). Compare via OrderedFloat
+        // rather than a bare partial_cmp().unwrap(): a malformed feed (e.g.
+        // best_bid == 0.0, making spread_pct divide-by-zero) can produce a
+        // NaN score, which would otherwise panic here.
+        scores.sort_by_key(|s| std::cmp::Reverse(OrderedFloat(s.score)));
         scores
     }
 
+    /// Find cross-exchange arbitrage: for each pair, the exchange with the
+    /// lowest ask (buy side) versus the exchange with the highest bid (sell
+    /// side). Sorted descending by gross edge.
+    pub fn compute_cross_exchange(&self) -> Vec<ArbitrageOpportunity> {
+        let mut by_pair: HashMap<String, Vec<OrderBook>> = HashMap::new();
+        for orderbook in self.store.all() {
+            by_pair
+                .entry(orderbook.pair.clone())
+                .or_default()
+                .push(orderbook);
+        }
+
+        let mut opportunities = Vec::new();
+
+        for (pair, books) in by_pair {
+            // Compare via OrderedFloat rather than a bare partial_cmp().unwrap():
+            // a feed that ever sends a non-comparable price (e.g. NaN) would
+            // otherwise panic this path instead of just sorting oddly.
+            let cheapest_ask = books
+                .iter()
+                .filter_map(|b| b.best_ask().map(|ask| (b, ask)))
+                .min_by_key(|(_, ask)| OrderedFloat(*ask));
+
+            let richest_bid = books
+                .iter()
+                .filter_map(|b| b.best_bid().map(|bid| (b, bid)))
+                .max_by_key(|(_, bid)| OrderedFloat(*bid));
+
+            let (Some((buy_book, buy_price)), Some((sell_book, sell_price))) =
+                (cheapest_ask, richest_bid)
+            else {
+                continue;
+            };
+
+            // Same exchange on both sides isn't cross-exchange arbitrage —
+            // that's just the internal spread already covered by compute_scores.
+            if buy_book.exchange == sell_book.exchange {
+                continue;
+            }
+
+            let edge_pct = (sell_price - buy_price) / buy_price * 100.0;
+            if edge_pct <= 0.0 {
+                continue;
+            }
+
+            let executable_qty = buy_book
+                .best_ask_qty()
+                .unwrap_or(0.0)
+                .min(sell_book.best_bid_qty().unwrap_or(0.0));
+            let quote_size = executable_qty * buy_price;
+
+            opportunities.push(ArbitrageOpportunity {
+                pair,
+                buy_exchange: buy_book.exchange.to_string(),
+                sell_exchange: sell_book.exchange.to_string(),
+                buy_price,
+                sell_price,
+                edge_pct,
+                quote_size,
+            });
+        }
+
+        opportunities.sort_by_key(|o| std::cmp::Reverse(OrderedFloat(o.edge_pct)));
+        opportunities
+    }
+
     /// Formats a price with enough decimal places to always show
     /// at least 4 significant digits, regardless of magnitude.
     /// e.g. 68074.30 → "68074.30", 0.00002341 → "0.00002341".
     /// This helps tickers with smaller prices to not show as "0.0".
-    fn format_price(price: f64) -> String {
+    pub(crate) fn format_price(price: f64) -> String {
         if price == 0.0 {
             return "0.00".to_string();
         }
@@ -72,3 +202,136 @@ impl ScoringEngine {
         format!("{:.prec$}", price, prec = decimals)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::FundingRate;
+    use std::collections::BTreeMap;
+
+    fn book(
+        exchange: &'static str,
+        pair: &str,
+        bid: f64,
+        bid_qty: f64,
+        ask: f64,
+        ask_qty: f64,
+    ) -> OrderBook {
+        let mut bids = BTreeMap::new();
+        bids.insert(OrderedFloat(bid), bid_qty);
+        let mut asks = BTreeMap::new();
+        asks.insert(OrderedFloat(ask), ask_qty);
+
+        OrderBook {
+            exchange,
+            pair: pair.to_string(),
+            bids,
+            asks,
+            updated_ms: 0,
+        }
+    }
+
+    fn test_config() -> Config {
+        Config {
+            pairs: vec!["BTCUSDT".to_string()],
+            api_port: 3000,
+            taker_fee_pct: 0.04,
+            maker_fee_pct: 0.02,
+            funding_weight: 1.0,
+        }
+    }
+
+    #[test]
+    fn compute_scores_ranks_by_funding_minus_cost() {
+        let store = OrderBookStore::new();
+        store.update(book("binance", "BTCUSDT", 100.0, 1.0, 100.1, 1.0));
+        store.update(book("bybit", "BTCUSDT", 100.0, 1.0, 100.5, 1.0));
+
+        let funding_store = FundingRateStore::new();
+        funding_store.update(FundingRate {
+            exchange: "binance",
+            pair: "BTCUSDT".to_string(),
+            rate: 0.001,
+            next_funding_ms: 0,
+        });
+        funding_store.update(FundingRate {
+            exchange: "bybit",
+            pair: "BTCUSDT".to_string(),
+            rate: 0.001,
+            next_funding_ms: 0,
+        });
+
+        let engine = ScoringEngine::new(store, funding_store, test_config());
+        let scores = engine.compute_scores();
+
+        assert_eq!(scores.len(), 2);
+        // Binance has the tighter spread, so a higher (less negative) score.
+        assert_eq!(scores[0].exchange, "binance");
+        assert!(scores[0].score > scores[1].score);
+    }
+
+    #[test]
+    fn compute_scores_skips_books_missing_a_side() {
+        let store = OrderBookStore::new();
+        store.update(OrderBook {
+            exchange: "binance",
+            pair: "BTCUSDT".to_string(),
+            bids: BTreeMap::new(),
+            asks: BTreeMap::new(),
+            updated_ms: 0,
+        });
+
+        let engine = ScoringEngine::new(store, FundingRateStore::new(), test_config());
+        assert!(engine.compute_scores().is_empty());
+    }
+
+    #[test]
+    fn compute_cross_exchange_finds_buy_low_sell_high() {
+        let store = OrderBookStore::new();
+        store.update(book("binance", "BTCUSDT", 99.0, 1.0, 100.0, 1.0));
+        store.update(book("bybit", "BTCUSDT", 102.0, 0.5, 103.0, 1.0));
+
+        let engine = ScoringEngine::new(store, FundingRateStore::new(), test_config());
+        let opportunities = engine.compute_cross_exchange();
+
+        assert_eq!(opportunities.len(), 1);
+        let opp = &opportunities[0];
+        assert_eq!(opp.buy_exchange, "binance");
+        assert_eq!(opp.sell_exchange, "bybit");
+        assert_eq!(opp.buy_price, 100.0);
+        assert_eq!(opp.sell_price, 102.0);
+        // Executable qty is limited by the thinner side (bybit's bid qty).
+        assert_eq!(opp.quote_size, 0.5 * 100.0);
+    }
+
+    #[test]
+    fn compute_cross_exchange_skips_same_exchange() {
+        let store = OrderBookStore::new();
+        store.update(book("binance", "BTCUSDT", 99.0, 1.0, 100.0, 1.0));
+
+        let engine = ScoringEngine::new(store, FundingRateStore::new(), test_config());
+        assert!(engine.compute_cross_exchange().is_empty());
+    }
+
+    #[test]
+    fn compute_cross_exchange_skips_negative_edge() {
+        let store = OrderBookStore::new();
+        // Cheapest ask (binance, 105) is above the richest bid (bybit, 95) —
+        // no profitable direction exists.
+        store.update(book("binance", "BTCUSDT", 90.0, 1.0, 105.0, 1.0));
+        store.update(book("bybit", "BTCUSDT", 95.0, 1.0, 110.0, 1.0));
+
+        let engine = ScoringEngine::new(store, FundingRateStore::new(), test_config());
+        assert!(engine.compute_cross_exchange().is_empty());
+    }
+
+    #[test]
+    fn compute_cross_exchange_empty_store_yields_no_opportunities() {
+        let engine = ScoringEngine::new(
+            OrderBookStore::new(),
+            FundingRateStore::new(),
+            test_config(),
+        );
+        assert!(engine.compute_cross_exchange().is_empty());
+    }
+}